@@ -1,5 +1,32 @@
 # noqa: E501
+from types import SimpleNamespace
+
+import numpy as np
+import pytest
+
 from wtpsplit import WtP
+from wtpsplit.extract import ORTWrapper, extract, validate_window_args
+from wtpsplit.utils import Constants
+
+
+class StubORTSession:
+    def __init__(self, input_names, num_labels=3):
+        self.declared_inputs = input_names
+        self.num_labels = num_labels
+        self.calls = []
+
+    def get_inputs(self):
+        return [SimpleNamespace(name=name) for name in self.declared_inputs]
+
+    def run(self, output_names, inputs):
+        self.calls.append(inputs)
+        n, length = inputs["attention_mask"].shape
+        return [np.zeros((n, length, self.num_labels), dtype=np.float32)]
+
+
+def stub_ort_model(input_names, language_adapter="on"):
+    config = SimpleNamespace(num_hash_functions=2, num_hash_buckets=16, num_labels=3, language_adapter=language_adapter)
+    return ORTWrapper(config, StubORTSession(input_names))
 
 
 def test_split_ort():
@@ -8,6 +35,43 @@ def test_split_ort():
     splits = wtp.split("This is a test sentence This is another test sentence.", threshold=0.005)
     assert splits == ["This is a test sentence ", "This is another test sentence."]
 
+def test_ort_language_ids_forwarded():
+    model = stub_ort_model(["attention_mask", "hashed_ids", "language_ids"])
+    hashed_ids = np.zeros((2, 4, 2), dtype=np.int64)
+    attention_mask = np.ones((2, 4), dtype=np.float32)
+
+    model(hashed_ids, attention_mask)
+    assert "language_ids" not in model.ort_session.calls[-1]
+
+    model(hashed_ids, attention_mask, language_ids=np.array([1, 2], dtype=np.int32))
+    assert model.ort_session.calls[-1]["language_ids"].dtype == np.int64
+    assert model.ort_session.calls[-1]["language_ids"].tolist() == [1, 2]
+
+
+def test_ort_language_adapters_require_input():
+    model = stub_ort_model(["attention_mask", "hashed_ids"])
+
+    with pytest.raises(ValueError, match="language_ids"):
+        extract(["This is a test sentence."], model, stride=8, block_size=16, batch_size=2, lang_code="en")
+
+
+def test_ort_language_ids_padded_last_batch():
+    model = stub_ort_model(["attention_mask", "hashed_ids", "language_ids"])
+    texts = ["a" * 10, "b" * 10, "c" * 10]
+
+    extract(texts, model, stride=5, block_size=10, batch_size=2, lang_code="en", pad_last_batch=True)
+
+    assert len(model.ort_session.calls) == 2
+    for inputs in model.ort_session.calls:
+        assert len(inputs["language_ids"]) == len(inputs["hashed_ids"]) == 2
+        assert (inputs["language_ids"] == Constants.LANG_CODE_TO_INDEX["en"]).all()
+
+    model.ort_session.calls.clear()
+    extract(texts, model, stride=5, block_size=10, batch_size=2, lang_code="en")
+
+    assert len(model.ort_session.calls[-1]["language_ids"]) == len(model.ort_session.calls[-1]["hashed_ids"]) == 1
+
+
 def test_split_torch():
     wtp = WtP("benjamin/wtp-bert-mini", hub_prefix=None)
 
@@ -39,6 +103,32 @@ def test_split_long():
     assert splits == [prefix + " " + "This is a test sentence. ", "This is another test sentence."]
 
 
+def test_validate_window_args():
+    # the text needs more than one chunk, so stride > block_size would skip characters
+    with pytest.raises(ValueError, match="stride"):
+        validate_window_args([64], stride=32, block_size=16, batch_size=32)
+
+    with pytest.raises(ValueError, match="stride"):
+        validate_window_args([64], stride=0, block_size=16, batch_size=32)
+
+    with pytest.raises(ValueError, match="block_size"):
+        validate_window_args([64], stride=8, block_size=0, batch_size=32)
+
+    with pytest.raises(ValueError, match="batch_size"):
+        validate_window_args([64], stride=8, block_size=16, batch_size=0)
+
+    # a text shorter than block_size is a single chunk, so a larger stride is fine
+    validate_window_args([64], stride=256, block_size=128, batch_size=32)
+
+
+def test_split_invalid_stride():
+    wtp = WtP("benjamin/wtp-bert-mini", hub_prefix=None)
+
+    # lists are validated before the generator is returned, not when it is iterated
+    with pytest.raises(ValueError, match="stride"):
+        wtp.split(["This is a test sentence.", "This is another test sentence."], stride=0)
+
+
 def test_split_batched():
     wtp = WtP("benjamin/wtp-bert-mini", hub_prefix=None)
 
@@ -12,7 +12,7 @@ import skops.io as sio
 from transformers import AutoConfig, AutoModelForTokenClassification
 from transformers.utils.hub import cached_file
 
-from wtpsplit.extract import ORTWrapper, PyTorchWrapper, extract
+from wtpsplit.extract import ORTWrapper, PyTorchWrapper, extract, validate_window_args
 from wtpsplit.utils import Constants, indices_to_sentences, sigmoid
 
 __version__ = "1.3.0"
@@ -129,6 +129,10 @@ class WtP:
         return_paragraph_probabilities=False,
         verbose: bool = False,
     ):
+        # validate eagerly, `_predict_proba` is a generator and would only fail once iterated
+        texts = [text_or_texts] if isinstance(text_or_texts, str) else text_or_texts
+        validate_window_args([len(text) for text in texts], stride=stride, block_size=block_size, batch_size=batch_size)
+
         if isinstance(text_or_texts, str):
             return next(
                 self._predict_proba(
@@ -269,6 +273,10 @@ class WtP:
         do_paragraph_segmentation=False,
         verbose: bool = False,
     ):
+        # validate eagerly, `_split` is a generator and would only fail once iterated
+        texts = [text_or_texts] if isinstance(text_or_texts, str) else text_or_texts
+        validate_window_args([len(text) for text in texts], stride=stride, block_size=block_size, batch_size=batch_size)
+
         if isinstance(text_or_texts, str):
             return next(
                 self._split(
@@ -355,7 +363,7 @@ class WtP:
 
         for text, probs in zip(
             texts,
-            self.predict_proba(
+            self._predict_proba(
                 texts,
                 lang_code=lang_code,
                 style=style,
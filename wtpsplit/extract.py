@@ -16,14 +16,20 @@ class ORTWrapper:
         assert hasattr(self, "ort_session")
         return getattr(self.ort_session, name)
 
-    def __call__(self, hashed_ids, attention_mask):
-        logits = self.ort_session.run(
-            ["logits"],
-            {
-                "attention_mask": attention_mask.astype(np.float16), # ORT expects fp16 mask
-                "hashed_ids": hashed_ids,
-            },
-        )[0]
+    @property
+    def input_names(self):
+        return {x.name for x in self.ort_session.get_inputs()}
+
+    def __call__(self, hashed_ids, attention_mask, language_ids=None):
+        inputs = {
+            "attention_mask": attention_mask.astype(np.float16), # ORT expects fp16 mask
+            "hashed_ids": hashed_ids,
+        }
+        # only passed for sessions which declare a `language_ids` input
+        if language_ids is not None:
+            inputs["language_ids"] = language_ids.astype(np.int64)
+
+        logits = self.ort_session.run(["logits"], inputs)[0]
 
         return {"logits": logits}
 
@@ -58,6 +64,24 @@ class PyTorchWrapper:
 
         return {"logits": logits}
 
+def validate_window_args(text_lengths, stride, block_size, batch_size):
+    if block_size <= 0:
+        raise ValueError(f"`block_size` must be positive, got {block_size}.")
+
+    if batch_size <= 0:
+        raise ValueError(f"`batch_size` must be positive, got {batch_size}.")
+
+    if stride <= 0:
+        raise ValueError(f"`stride` must be positive, got {stride}.")
+
+    # only texts longer than `block_size` are sliced into multiple chunks. for those, stride > block_size
+    # would leave some characters outside of every chunk
+    if stride > block_size and max(text_lengths, default=0) > block_size:
+        raise ValueError(
+            f"`stride` ({stride}) must not be larger than `block_size` ({block_size}) "
+            "when texts are longer than `block_size`."
+        )
+
 def extract(
     batch_of_texts,
     model,
@@ -78,6 +102,8 @@ def extract(
     """
 
     text_lengths = [len(text) for text in batch_of_texts]
+    validate_window_args(text_lengths, stride=stride, block_size=block_size, batch_size=batch_size)
+
     # reduce block size if possible
     block_size = min(block_size, max(text_lengths))
 
@@ -151,8 +177,8 @@ def extract(
         if lang_code is None:
             raise ValueError("Please specify a `lang_code` when using a model with language adapters.")
 
-        if isinstance(model, ORTWrapper):
-            raise ValueError("Language adapters are not supported in ONNX models.")
+        if isinstance(model, ORTWrapper) and "language_ids" not in model.input_names:
+            raise ValueError("Language adapters are not supported in ONNX models without a `language_ids` input.")
 
         language_ids = np.array(
             [Constants.LANG_CODE_TO_INDEX[lang_code]] * batch_size,